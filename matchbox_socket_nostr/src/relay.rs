@@ -0,0 +1,142 @@
+use std::collections::{HashSet, VecDeque};
+
+use log::debug;
+use nostr::EventId;
+
+use crate::WebRtcSocketBuilder;
+
+/// The number of recently seen nostr event IDs retained for deduplication.
+///
+/// Keeping this bounded means the signaling loop can fan out across an
+/// arbitrary number of relays without the seen-set growing without limit.
+const SEEN_EVENTS_CAPACITY: usize = 1024;
+
+/// A bounded, least-recently-used set of nostr event IDs used to drop
+/// signaling events that arrive more than once.
+///
+/// When the same offer/answer/ICE-candidate event is multiplexed across
+/// several relays it will be delivered multiple times. [`SeenEvents`] lets the
+/// receive side keep only the first copy and discard the rest before handing
+/// them to the peer-connection state machines.
+#[derive(Debug)]
+pub(crate) struct SeenEvents {
+    ids: HashSet<EventId>,
+    order: VecDeque<EventId>,
+    capacity: usize,
+}
+
+impl SeenEvents {
+    /// Creates a [`SeenEvents`] set retaining the default number of event IDs.
+    pub(crate) fn new() -> Self {
+        Self::with_capacity(SEEN_EVENTS_CAPACITY)
+    }
+
+    /// Creates a [`SeenEvents`] set retaining up to `capacity` event IDs.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records `id` as seen and returns `true` if it is a duplicate that should
+    /// be dropped, i.e. it had already been seen.
+    ///
+    /// Re-seeing an ID refreshes its recency so that a hot, repeatedly-arriving
+    /// event is not evicted while still in flight.
+    pub(crate) fn is_duplicate(&mut self, id: EventId) -> bool {
+        if self.ids.contains(&id) {
+            debug!("dropping duplicate signaling event {id}");
+            // Refresh recency: move the id to the most-recently-seen position.
+            if let Some(pos) = self.order.iter().position(|seen| *seen == id) {
+                self.order.remove(pos);
+            }
+            self.order.push_back(id);
+            return true;
+        }
+
+        if self.capacity == 0 {
+            return false;
+        }
+
+        if self.order.len() == self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.ids.remove(&evicted);
+            }
+        }
+
+        self.ids.insert(id);
+        self.order.push_back(id);
+        false
+    }
+}
+
+impl Default for SeenEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> WebRtcSocketBuilder<C> {
+    /// Adds a single nostr relay to the set of relays used for signaling.
+    ///
+    /// Every signaling event is fanned out to all configured relays and the
+    /// socket subscribes to all of them, so that peer discovery survives any
+    /// one relay going down.
+    pub fn add_relay(mut self, relay: impl Into<String>) -> Self {
+        self.config.relays.push(relay.into());
+        self
+    }
+
+    /// Replaces the set of nostr relays used for signaling.
+    ///
+    /// Every signaling event is fanned out to all configured relays and the
+    /// socket subscribes to all of them, so that peer discovery survives any
+    /// one relay going down.
+    pub fn with_relays(mut self, relays: Vec<String>) -> Self {
+        self.config.relays = relays;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_id(byte: u8) -> EventId {
+        EventId::from_slice(&[byte; 32]).unwrap()
+    }
+
+    #[test]
+    fn detects_duplicates() {
+        let mut seen = SeenEvents::new();
+        let id = event_id(1);
+        assert!(!seen.is_duplicate(id), "first sighting is not a duplicate");
+        assert!(seen.is_duplicate(id), "second sighting is a duplicate");
+        assert!(seen.is_duplicate(id), "further sightings are duplicates");
+    }
+
+    #[test]
+    fn evicts_oldest_at_capacity() {
+        let mut seen = SeenEvents::with_capacity(2);
+        seen.is_duplicate(event_id(1));
+        seen.is_duplicate(event_id(2));
+        // Inserting a third id evicts the oldest (id 1).
+        seen.is_duplicate(event_id(3));
+        assert!(!seen.is_duplicate(event_id(1)), "oldest id was evicted");
+        assert!(seen.is_duplicate(event_id(3)), "newest id is retained");
+    }
+
+    #[test]
+    fn reseeing_refreshes_recency() {
+        let mut seen = SeenEvents::with_capacity(2);
+        seen.is_duplicate(event_id(1));
+        seen.is_duplicate(event_id(2));
+        // Re-seeing id 1 makes id 2 the least-recently-seen.
+        assert!(seen.is_duplicate(event_id(1)));
+        seen.is_duplicate(event_id(3));
+        assert!(seen.is_duplicate(event_id(1)), "refreshed id survived eviction");
+        assert!(!seen.is_duplicate(event_id(2)), "stale id was evicted instead");
+    }
+}